@@ -7,7 +7,7 @@ use light_sdk::cpi::{LightCpiInstruction, InvokeLightSystemProgram};
 use light_sdk::derive_light_cpi_signer;
 use light_sdk::instruction::{ValidityProof, CompressedProof};
 use light_sdk::{LightAccount, LightDiscriminator};
-use light_sdk::address::NewAddressParamsAssignedPacked;
+use light_sdk::address::{derive_address, NewAddressParamsAssignedPacked};
 use light_hasher::Hasher;
 
 // Replace with your deployed program ID after `anchor deploy`
@@ -18,6 +18,112 @@ declare_id!("FqnkaXZkLJfMZbrx36qBnuSZcJAaktguuhp32mqmAKAo");
 pub const LIGHT_CPI_SIGNER: CpiSigner =
     derive_light_cpi_signer!("FqnkaXZkLJfMZbrx36qBnuSZcJAaktguuhp32mqmAKAo");
 
+/// Maximum number of registries that can be minted in a single
+/// `create_compressed_nft_batch` call. Bounds the number of light accounts
+/// packed into one CPI so the instruction stays under the compute budget.
+pub const MAX_BATCH_SIZE: usize = 10;
+
+/// Maximum number of owners a multisig `NFTRegistry` can register.
+pub const MAX_OWNERS: usize = 5;
+
+/// Validate an owner set / threshold pair before it is written into a registry:
+/// `1 <= threshold <= owners.len() <= MAX_OWNERS` and no duplicate owner keys.
+fn validate_owner_set(owners: &[[u8; 32]], threshold: u8) -> Result<()> {
+    require!(!owners.is_empty(), ErrorCode::InvalidMultisigConfig);
+    require!(owners.len() <= MAX_OWNERS, ErrorCode::InvalidMultisigConfig);
+    require!(threshold >= 1, ErrorCode::InvalidMultisigConfig);
+    require!(
+        (threshold as usize) <= owners.len(),
+        ErrorCode::InvalidMultisigConfig
+    );
+    for (i, owner) in owners.iter().enumerate() {
+        require!(
+            !owners[..i].contains(owner),
+            ErrorCode::DuplicateOwner
+        );
+    }
+    Ok(())
+}
+
+/// Upper bound (exclusive) for `diversifier_index`, matching the ZIP32
+/// 88-bit diversifier index bound.
+pub const MAX_DIVERSIFIER_INDEX: u128 = 1u128 << 88;
+
+/// Maximum number of `u64` segments in a `derive_address_seed` path. Each
+/// segment is a sequential Poseidon hash, so this bounds the compute budget
+/// `derive_address_seed` can spend - important since the fee payer sponsoring
+/// a mint (e.g. a relayer) isn't necessarily the one choosing the path.
+pub const MAX_DERIVATION_PATH_LEN: usize = 8;
+
+/// Where an instruction's `address_seed` comes from: a raw client-supplied
+/// seed, or a ZIP32-style hierarchical derivation rooted at a master seed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum AddressSeedSource {
+    /// Client-supplied raw 32-byte seed (legacy path, no derivation)
+    Raw([u8; 32]),
+    /// Derive the seed via `derive_address_seed(master_seed, path, diversifier_index)`
+    Derived {
+        master_seed: [u8; 32],
+        path: Vec<u64>,
+        diversifier_index: u128,
+    },
+}
+
+/// Derive a compressed-account address seed from a hierarchical path.
+///
+/// Mirrors a ZIP32 derivation: each `u64` in `path` folds the running seed
+/// through `Poseidon::hashv(&[seed, index_le_bytes])`, so callers can derive
+/// `m/collection/item`-style paths (e.g. `path = [collection_index, item_index]`)
+/// entirely inside this Poseidon chain, up to `MAX_DERIVATION_PATH_LEN` segments -
+/// enforced here so both `create_compressed_nft` and `prepare_compressed_nft`,
+/// the two instructions that accept a path from instruction data, are protected.
+/// `diversifier_index` is folded in last so one `master_seed` yields many
+/// unlinkable addresses; it must stay below `MAX_DIVERSIFIER_INDEX` (2^88),
+/// matching the ZIP32 diversifier bound.
+fn derive_address_seed(
+    master_seed: &[u8; 32],
+    path: &[u64],
+    diversifier_index: u128,
+) -> Result<[u8; 32]> {
+    use light_hasher::Poseidon;
+
+    require!(
+        path.len() <= MAX_DERIVATION_PATH_LEN,
+        ErrorCode::DerivationPathTooLong
+    );
+    require!(
+        diversifier_index < MAX_DIVERSIFIER_INDEX,
+        ErrorCode::InvalidDiversifierIndex
+    );
+
+    let mut seed = *master_seed;
+    for index in path {
+        seed = Poseidon::hashv(&[&seed, &index.to_le_bytes()]).map_err(|e| {
+            msg!("Poseidon derivation step failed: {:?}", e);
+            error!(ErrorCode::SeedDerivationFailed)
+        })?;
+    }
+    seed = Poseidon::hashv(&[&seed, &diversifier_index.to_le_bytes()]).map_err(|e| {
+        msg!("Poseidon diversifier fold failed: {:?}", e);
+        error!(ErrorCode::SeedDerivationFailed)
+    })?;
+
+    Ok(seed)
+}
+
+/// Derive a Light Protocol compressed-account address from the owning
+/// program, the address tree it will live in, and an address seed. Used by
+/// `prepare_compressed_nft` so the address can be computed offline, before a
+/// validity proof has even been fetched.
+///
+/// Delegates to `light_sdk::address::derive_address` - the same routine the
+/// Light System Program uses internally - rather than hand-rolling the hash,
+/// so the emitted address is guaranteed to match what `create_compressed_nft`
+/// actually creates on-chain.
+fn derive_compressed_address(program_id: &Pubkey, address_tree: &Pubkey, seed: &[u8; 32]) -> [u8; 32] {
+    derive_address(seed, address_tree, program_id)
+}
+
 #[program]
 pub mod light_nft_reproducer {
     use super::*;
@@ -35,7 +141,8 @@ pub mod light_nft_reproducer {
     /// * `address_tree_root_index` - Root index for address tree
     /// * `address_tree_account_index` - Index of address tree in remaining_accounts
     /// * `output_queue_index` - Index of output queue in remaining_accounts (V2 batch trees)
-    /// * `address_seed` - Seed for deriving compressed account address
+    /// * `address_seed` - Either a raw client-supplied seed, or a hierarchical
+    ///   `(master_seed, path, diversifier_index)` derived via `derive_address_seed`
     ///
     /// # Remaining Accounts (V2 ORDER - CRITICAL!)
     /// * [0+] Light Protocol accounts in V2 order
@@ -50,12 +157,27 @@ pub mod light_nft_reproducer {
         address_tree_root_index: u16,
         address_tree_account_index: u8,
         output_queue_index: u8,
-        address_seed: [u8; 32],
+        address_seed: AddressSeedSource,
     ) -> Result<()> {
         msg!("=== Light Protocol V2 CPI Reproducer ===");
         msg!("Creating compressed NFT registry: {}", name);
         msg!("Symbol: {}, URI length: {}", symbol, uri.len());
 
+        // Raw seeds are passed through as-is; hierarchical paths are folded
+        // through the Poseidon derivation chain so address derivation is
+        // reproducible and auditable offline.
+        let address_seed = match address_seed {
+            AddressSeedSource::Raw(seed) => seed,
+            AddressSeedSource::Derived {
+                master_seed,
+                path,
+                diversifier_index,
+            } => {
+                msg!("Deriving address seed from path of length {}", path.len());
+                derive_address_seed(&master_seed, &path, diversifier_index)?
+            }
+        };
+
         // Log remaining_accounts for debugging
         msg!("Remaining accounts count: {}", ctx.remaining_accounts.len());
         for (i, acc) in ctx.remaining_accounts.iter().enumerate() {
@@ -129,7 +251,9 @@ pub mod light_nft_reproducer {
             output_queue_absolute_index,
         );
         msg!("Output queue index: {} (absolute)", output_queue_absolute_index);
-        registry.owner = owner_bytes;
+        registry.owners[0] = owner_bytes;
+        registry.owner_count = 1;
+        registry.threshold = 1; // single-signer registry: 1-of-1 multisig
         registry.name = name_bytes;
         registry.symbol = symbol_bytes;
         registry.uri_hash = uri_hash;
@@ -158,6 +282,432 @@ pub mod light_nft_reproducer {
         msg!("=== Compressed NFT registry created successfully! ===");
         Ok(())
     }
+
+    /// Create many compressed NFT registries in a single Light System Program CPI
+    ///
+    /// Amortizes the fixed cost of the CPI and the validity proof across the whole
+    /// batch: Photon's `getValidityProof` already returns one combined proof for
+    /// multiple new addresses, so `metas.len()` registries are packed into one
+    /// `InstructionDataInvokeCpiWithAccountInfo` and invoked once.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing user signer and remaining_accounts
+    /// * `metas` - Per-registry name/symbol/uri, one entry per minted NFT
+    /// * `address_seeds` - Address-tree seeds, one per entry in `metas` (same order)
+    /// * `proof_a` / `proof_b` / `proof_c` - Single validity proof covering all new addresses.
+    ///   This is an opaque SNARK proof with no client-readable address count, so it can't be
+    ///   checked against `metas.len()` here; a proof generated for a different address count
+    ///   still fails Light System Program's own verification inside `invoke()` below.
+    /// * `expected_new_address_count` - The address count the caller's `proof_a/b/c` was
+    ///   generated for. Must equal `metas.len()`; this is a cheap client-side fail-fast
+    ///   that surfaces a clear `ErrorCode` for a malformed call instead of only the
+    ///   generic `CpiInvokeFailed` the CPI itself would return.
+    /// * `address_tree_root_index` - Root index for address tree (shared by the whole batch)
+    ///
+    /// # Remaining Accounts (V2 ORDER - CRITICAL!)
+    /// Same layout as `create_compressed_nft`; every registry in the batch shares the
+    /// same address tree (`remaining_accounts[8]`) and output queue (`remaining_accounts[9]`).
+    pub fn create_compressed_nft_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCompressedNFTBatch<'info>>,
+        metas: Vec<NftMeta>,
+        address_seeds: Vec<[u8; 32]>,
+        proof_a: [u8; 32],
+        proof_b: [u8; 64],
+        proof_c: [u8; 32],
+        expected_new_address_count: u32,
+        address_tree_root_index: u16,
+    ) -> Result<()> {
+        msg!("=== Light Protocol V2 Batch CPI Reproducer ===");
+
+        require!(!metas.is_empty(), ErrorCode::EmptyBatch);
+        require!(metas.len() <= MAX_BATCH_SIZE, ErrorCode::BatchTooLarge);
+        require_eq!(
+            metas.len(),
+            address_seeds.len(),
+            ErrorCode::AddressSeedCountMismatch
+        );
+        // Fail-fast: catches a proof generated for the wrong number of new
+        // addresses with a clear error, rather than only the generic
+        // `CpiInvokeFailed` Light System Program's own verification would
+        // return from deep inside `invoke()` below.
+        require_eq!(
+            metas.len() as u32,
+            expected_new_address_count,
+            ErrorCode::NewAddressCountMismatch
+        );
+        msg!("Batch size: {}", metas.len());
+        msg!("Remaining accounts count: {}", ctx.remaining_accounts.len());
+
+        // Absolute indices in remaining_accounts (V2 layout), shared by every
+        // registry in the batch - see create_compressed_nft for the full ordering.
+        let address_tree_absolute_index = 8u8;
+        let output_queue_absolute_index = 9u8;
+        require!(
+            ctx.remaining_accounts.len() > output_queue_absolute_index as usize,
+            ErrorCode::InsufficientRemainingAccounts
+        );
+
+        let cpi_accounts = CpiAccounts::new(
+            ctx.accounts.user.as_ref(), // fee_payer reference
+            ctx.remaining_accounts,     // accounts in V2 order
+            LIGHT_CPI_SIGNER,
+        );
+
+        // Single aggregated validity proof, covering every new address in the batch.
+        // `CompressedProof` is just three opaque SNARK field elements - it carries no
+        // client-readable new-address count to check against `metas.len()` here.
+        // `new_address_params` below is always built with exactly `metas.len()`
+        // entries, and the Light System Program verifies `proof` against that exact
+        // set of addresses when `invoke()` runs the CPI, so a proof generated for a
+        // different address count is rejected on-chain rather than client-side.
+        let compressed_proof = CompressedProof {
+            a: proof_a,
+            b: proof_b,
+            c: proof_c,
+        };
+        let proof = ValidityProof(Some(compressed_proof));
+        msg!("Aggregated validity proof constructed for {} new addresses", metas.len());
+
+        let mut owner_bytes = [0u8; 32];
+        owner_bytes.copy_from_slice(ctx.accounts.user.key.as_ref());
+
+        let mut new_address_params = Vec::with_capacity(metas.len());
+        let mut cpi_instruction =
+            InstructionDataInvokeCpiWithAccountInfo::new_cpi(LIGHT_CPI_SIGNER, proof);
+
+        for (meta, seed) in metas.iter().zip(address_seeds.iter()) {
+            let mut name_bytes = [0u8; 32];
+            let mut symbol_bytes = [0u8; 10];
+
+            let name_len = meta.name.len().min(32);
+            let symbol_len = meta.symbol.len().min(10);
+            name_bytes[..name_len].copy_from_slice(&meta.name.as_bytes()[..name_len]);
+            symbol_bytes[..symbol_len].copy_from_slice(&meta.symbol.as_bytes()[..symbol_len]);
+
+            let uri_hash = hash_to_32_bytes(meta.uri.as_bytes());
+
+            let mut registry = LightAccount::<NFTRegistry>::new_init(
+                &crate::ID,
+                None, // Address derived by Light Protocol
+                output_queue_absolute_index,
+            );
+            registry.owners[0] = owner_bytes;
+            registry.owner_count = 1;
+            registry.threshold = 1; // single-signer registry: 1-of-1 multisig
+            registry.name = name_bytes;
+            registry.symbol = symbol_bytes;
+            registry.uri_hash = uri_hash;
+
+            cpi_instruction = cpi_instruction
+                .with_light_account(registry)
+                .map_err(|e| {
+                    msg!("Failed to add light account to batch: {:?}", e);
+                    error!(ErrorCode::LightAccountError)
+                })?;
+
+            new_address_params.push(NewAddressParamsAssignedPacked {
+                seed: *seed,
+                address_queue_account_index: 0, // V2: 0 = integrated queue
+                address_merkle_tree_account_index: address_tree_absolute_index,
+                address_merkle_tree_root_index: address_tree_root_index,
+                assigned_to_account: false,
+                assigned_account_index: 0,
+            });
+        }
+
+        msg!(
+            "Invoking Light System Program V2 CPI once for {} registries...",
+            metas.len()
+        );
+
+        cpi_instruction
+            .with_new_addresses(&new_address_params)
+            .invoke(cpi_accounts)
+            .map_err(|e| {
+                msg!("Batch CPI invoke failed: {:?}", e);
+                error!(ErrorCode::CpiInvokeFailed)
+            })?;
+
+        msg!(
+            "=== Batch of {} compressed NFT registries created successfully! ===",
+            metas.len()
+        );
+        Ok(())
+    }
+
+    /// Create a compressed NFT registry owned by an M-of-N multisig
+    ///
+    /// Same V2 CPI flow as `create_compressed_nft`, except `owners`/`threshold`
+    /// are written into the registry instead of defaulting to the single caller.
+    ///
+    /// # Arguments
+    /// * `owners` - Registered owner pubkeys (`1..=MAX_OWNERS`, no duplicates)
+    /// * `threshold` - Number of owner signatures required to transfer the registry
+    /// * `address_seed` - Raw 32-byte address seed; unlike `create_compressed_nft`,
+    ///   hierarchical `derive_address_seed` paths are not accepted here
+    /// * `name`, `symbol`, `uri`, `proof_a/b/c`, `address_tree_root_index` - as in
+    ///   `create_compressed_nft`
+    pub fn create_compressed_multisig_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCompressedMultisigNFT<'info>>,
+        name: String,
+        symbol: String,
+        uri: String,
+        owners: Vec<[u8; 32]>,
+        threshold: u8,
+        proof_a: [u8; 32],
+        proof_b: [u8; 64],
+        proof_c: [u8; 32],
+        address_tree_root_index: u16,
+        address_seed: [u8; 32],
+    ) -> Result<()> {
+        msg!("=== Light Protocol V2 CPI Reproducer: multisig mint ===");
+        validate_owner_set(&owners, threshold)?;
+        msg!("Owners: {}, threshold: {}", owners.len(), threshold);
+
+        let cpi_accounts = CpiAccounts::new(
+            ctx.accounts.user.as_ref(),
+            ctx.remaining_accounts,
+            LIGHT_CPI_SIGNER,
+        );
+
+        let compressed_proof = CompressedProof {
+            a: proof_a,
+            b: proof_b,
+            c: proof_c,
+        };
+        let proof = ValidityProof(Some(compressed_proof));
+
+        // Address Tree at remaining_accounts[8]
+        let address_tree_absolute_index = 8u8;
+        let new_address_params = NewAddressParamsAssignedPacked {
+            seed: address_seed,
+            address_queue_account_index: 0, // V2: 0 = integrated queue
+            address_merkle_tree_account_index: address_tree_absolute_index,
+            address_merkle_tree_root_index: address_tree_root_index,
+            assigned_to_account: false,
+            assigned_account_index: 0,
+        };
+
+        let mut name_bytes = [0u8; 32];
+        let mut symbol_bytes = [0u8; 10];
+        let name_len = name.len().min(32);
+        let symbol_len = symbol.len().min(10);
+        name_bytes[..name_len].copy_from_slice(&name.as_bytes()[..name_len]);
+        symbol_bytes[..symbol_len].copy_from_slice(&symbol.as_bytes()[..symbol_len]);
+        let uri_hash = hash_to_32_bytes(uri.as_bytes());
+
+        // Output Queue at remaining_accounts[9]
+        let output_queue_absolute_index = 9u8;
+        let mut registry = LightAccount::<NFTRegistry>::new_init(
+            &crate::ID,
+            None, // Address derived by Light Protocol
+            output_queue_absolute_index,
+        );
+        let mut owner_slots = [[0u8; 32]; MAX_OWNERS];
+        owner_slots[..owners.len()].copy_from_slice(&owners);
+        registry.owners = owner_slots;
+        registry.owner_count = owners.len() as u8;
+        registry.threshold = threshold;
+        registry.name = name_bytes;
+        registry.symbol = symbol_bytes;
+        registry.uri_hash = uri_hash;
+
+        msg!("Invoking Light System Program V2 CPI (AccountInfo variant)...");
+
+        InstructionDataInvokeCpiWithAccountInfo::new_cpi(LIGHT_CPI_SIGNER, proof)
+            .with_light_account(registry)
+            .map_err(|e| {
+                msg!("Failed to add light account: {:?}", e);
+                error!(ErrorCode::LightAccountError)
+            })?
+            .with_new_addresses(&[new_address_params])
+            .invoke(cpi_accounts)
+            .map_err(|e| {
+                msg!("CPI invoke failed: {:?}", e);
+                error!(ErrorCode::CpiInvokeFailed)
+            })?;
+
+        msg!("=== Multisig compressed NFT registry created successfully! ===");
+        Ok(())
+    }
+
+    /// Transfer a compressed NFT registry to a new owner set
+    ///
+    /// Opens the existing compressed account via `LightAccount::new_mut` (using
+    /// the stored address and the caller-supplied inclusion proof), requires at
+    /// least `current_state.threshold` of the registered owners to be signers
+    /// among `ctx.accounts`/`ctx.remaining_accounts`, then writes the new
+    /// `owners`/`threshold` back through the V2 CPI.
+    ///
+    /// # Remaining Accounts (V2 ORDER - CRITICAL!)
+    /// * [0..10) Light Protocol accounts, same layout as `create_compressed_nft`
+    /// * [10+] any owner signers not already covered by `ctx.accounts.authority`
+    pub fn transfer_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferCompressedNFT<'info>>,
+        address: [u8; 32],
+        current_state: NFTRegistrySnapshot,
+        new_owners: Vec<[u8; 32]>,
+        new_threshold: u8,
+        proof_a: [u8; 32],
+        proof_b: [u8; 64],
+        proof_c: [u8; 32],
+        merkle_tree_root_index: u16,
+        merkle_tree_account_index: u8,
+        leaf_index: u32,
+        output_queue_index: u8,
+    ) -> Result<()> {
+        msg!("=== Light Protocol V2 CPI Reproducer: transfer ===");
+        validate_owner_set(&current_state.owners, current_state.threshold)?;
+        validate_owner_set(&new_owners, new_threshold)?;
+
+        // Count how many of the CURRENT owners are signers of this transaction.
+        let mut signer_count = 0u8;
+        for owner in current_state.owners.iter() {
+            let signed = (ctx.accounts.authority.key.as_ref() == owner
+                && ctx.accounts.authority.is_signer)
+                || ctx
+                    .remaining_accounts
+                    .iter()
+                    .any(|acc| acc.is_signer && acc.key.as_ref() == owner);
+            if signed {
+                signer_count += 1;
+            }
+        }
+        msg!(
+            "{}/{} required owner signatures present",
+            signer_count,
+            current_state.threshold
+        );
+        require!(
+            signer_count >= current_state.threshold,
+            ErrorCode::ThresholdNotMet
+        );
+
+        let cpi_accounts = CpiAccounts::new(
+            ctx.accounts.authority.as_ref(),
+            ctx.remaining_accounts,
+            LIGHT_CPI_SIGNER,
+        );
+
+        let compressed_proof = CompressedProof {
+            a: proof_a,
+            b: proof_b,
+            c: proof_c,
+        };
+        let proof = ValidityProof(Some(compressed_proof));
+
+        // Re-open the existing compressed account: LightAccount::new_mut takes the
+        // same inclusion-proof coordinates the client already fetched to build
+        // `proof_a/b/c` (merkle tree account index + root index + leaf index),
+        // plus the output queue the rewritten account is appended to.
+        let mut registry = LightAccount::<NFTRegistry>::new_mut(
+            &crate::ID,
+            address,
+            current_state.clone().into_registry(),
+            merkle_tree_account_index,
+            merkle_tree_root_index,
+            leaf_index,
+            output_queue_index,
+        )
+        .map_err(|e| {
+            msg!("Failed to open existing light account: {:?}", e);
+            error!(ErrorCode::LightAccountError)
+        })?;
+
+        let mut owner_slots = [[0u8; 32]; MAX_OWNERS];
+        owner_slots[..new_owners.len()].copy_from_slice(&new_owners);
+        registry.owners = owner_slots;
+        registry.owner_count = new_owners.len() as u8;
+        registry.threshold = new_threshold;
+
+        msg!(
+            "Rewriting owners: {} owner(s), threshold={}",
+            registry.owner_count,
+            registry.threshold
+        );
+
+        InstructionDataInvokeCpiWithAccountInfo::new_cpi(LIGHT_CPI_SIGNER, proof)
+            .with_light_account(registry)
+            .map_err(|e| {
+                msg!("Failed to add light account: {:?}", e);
+                error!(ErrorCode::LightAccountError)
+            })?
+            .invoke(cpi_accounts)
+            .map_err(|e| {
+                msg!("CPI invoke failed: {:?}", e);
+                error!(ErrorCode::CpiInvokeFailed)
+            })?;
+
+        msg!("=== Compressed NFT registry ownership transferred successfully! ===");
+        Ok(())
+    }
+
+    /// Derive a future compressed account's address and the exact accounts a
+    /// following `create_compressed_nft` CPI will require, without invoking the
+    /// Light System Program. Mirrors an offline "prepare transaction" step: an
+    /// offline service reads the emitted `CompressedNftPrepared` event, calls
+    /// Photon's `getValidityProof` for the derived address, and feeds the proof
+    /// straight into `create_compressed_nft` - no more hardcoded
+    /// `remaining_accounts[8]`/`[9]` indices in client code.
+    ///
+    /// # Arguments
+    /// * `address_seed` - Same raw-or-derived seed `create_compressed_nft` accepts
+    ///
+    /// # Remaining Accounts (V2 ORDER - CRITICAL!)
+    /// * Same ordering `create_compressed_nft` expects; this instruction only
+    ///   reads the address tree (`remaining_accounts[8]`) and output queue
+    ///   (`remaining_accounts[9]`) out of it and echoes the rest. These indices
+    ///   are hardcoded - not caller-supplied - so the address/accounts this
+    ///   emits always match what `create_compressed_nft` will actually use.
+    pub fn prepare_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrepareCompressedNFT<'info>>,
+        address_seed: AddressSeedSource,
+    ) -> Result<()> {
+        msg!("=== Light Protocol V2 CPI Reproducer: prepare (no CPI) ===");
+
+        let seed = match address_seed {
+            AddressSeedSource::Raw(seed) => seed,
+            AddressSeedSource::Derived {
+                master_seed,
+                path,
+                diversifier_index,
+            } => derive_address_seed(&master_seed, &path, diversifier_index)?,
+        };
+
+        // Absolute indices in remaining_accounts (V2 layout) - must match
+        // create_compressed_nft exactly; see its comments for the full ordering.
+        let address_tree_absolute_index = 8u8;
+        let output_queue_absolute_index = 9u8;
+        let address_tree = ctx
+            .remaining_accounts
+            .get(address_tree_absolute_index as usize)
+            .ok_or_else(|| error!(ErrorCode::InsufficientRemainingAccounts))?;
+        let output_queue = ctx
+            .remaining_accounts
+            .get(output_queue_absolute_index as usize)
+            .ok_or_else(|| error!(ErrorCode::InsufficientRemainingAccounts))?;
+
+        let derived_address = derive_compressed_address(&crate::ID, address_tree.key, &seed);
+        let remaining_accounts_order = ctx
+            .remaining_accounts
+            .iter()
+            .map(|acc| *acc.key)
+            .collect::<Vec<_>>();
+
+        msg!("Derived address: {:?}", &derived_address[..8]);
+        msg!("Address tree: {}", address_tree.key);
+        msg!("Output queue: {}", output_queue.key);
+
+        emit!(CompressedNftPrepared {
+            derived_address,
+            address_tree: *address_tree.key,
+            output_queue: *output_queue.key,
+            remaining_accounts_order,
+        });
+
+        msg!("=== Compressed NFT prepared - no CPI invoked ===");
+        Ok(())
+    }
 }
 
 /// Accounts for creating a compressed NFT
@@ -178,12 +728,100 @@ pub struct CreateCompressedNFT<'info> {
     // This is INTENTIONAL - V2 CPI uses remaining_accounts for flexibility
 }
 
+/// Accounts for minting a batch of compressed NFTs in one Light System Program CPI
+///
+/// Same V2 remaining_accounts layout as `CreateCompressedNFT`; every registry in
+/// the batch is written through the same fee payer / address tree / output queue.
+#[derive(Accounts)]
+pub struct CreateCompressedNFTBatch<'info> {
+    /// The user creating the NFTs (pays for transaction)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    // V2 Light Protocol accounts passed via remaining_accounts
+    // This is INTENTIONAL - V2 CPI uses remaining_accounts for flexibility
+}
+
+/// Accounts for minting a multisig-owned compressed NFT
+///
+/// Same V2 remaining_accounts layout as `CreateCompressedNFT`.
+#[derive(Accounts)]
+pub struct CreateCompressedMultisigNFT<'info> {
+    /// The user paying for the transaction (not necessarily a registered owner)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    // V2 Light Protocol accounts passed via remaining_accounts
+    // This is INTENTIONAL - V2 CPI uses remaining_accounts for flexibility
+}
+
+/// Accounts for transferring ownership of a compressed NFT registry
+///
+/// Same V2 remaining_accounts layout as `CreateCompressedNFT`; any owner
+/// signers beyond `authority` are appended to `remaining_accounts`.
+#[derive(Accounts)]
+pub struct TransferCompressedNFT<'info> {
+    /// Fee payer for the transaction; also checked as a possible owner signer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    // V2 Light Protocol accounts passed via remaining_accounts
+    // This is INTENTIONAL - V2 CPI uses remaining_accounts for flexibility
+}
+
+/// Accounts for `prepare_compressed_nft`
+///
+/// Read-only: the Light Protocol accounts are only inspected for their pubkeys,
+/// never written to, since no CPI is invoked.
+#[derive(Accounts)]
+pub struct PrepareCompressedNFT<'info> {
+    /// The user who will submit the follow-up `create_compressed_nft` call
+    pub user: Signer<'info>,
+    // V2 Light Protocol accounts passed via remaining_accounts; only the
+    // address tree and output queue entries are read.
+}
+
+/// Emitted by `prepare_compressed_nft`: a portable description of what a
+/// subsequent `create_compressed_nft` call will need - the derived address to
+/// fetch a validity proof for, and the exact accounts/order that CPI requires.
+#[event]
+pub struct CompressedNftPrepared {
+    pub derived_address: [u8; 32],
+    pub address_tree: Pubkey,
+    pub output_queue: Pubkey,
+    pub remaining_accounts_order: Vec<Pubkey>,
+}
+
+/// Per-entry metadata for `create_compressed_nft_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftMeta {
+    /// NFT name (max 32 bytes)
+    pub name: String,
+    /// NFT symbol (max 10 bytes)
+    pub symbol: String,
+    /// NFT metadata URI (hashed for storage)
+    pub uri: String,
+}
+
 /// Compressed NFT Registry stored in Light Protocol state tree
 /// Uses LightDiscriminator for proper serialization
 #[derive(Clone, Debug, Default, LightDiscriminator, BorshSerialize, BorshDeserialize)]
 pub struct NFTRegistry {
-    /// Owner of the NFT
-    pub owner: [u8; 32],
+    /// Registered owner pubkeys; only the first `owner_count` entries are valid
+    pub owners: [[u8; 32]; MAX_OWNERS],
+    /// Number of valid entries in `owners`
+    pub owner_count: u8,
+    /// Number of owner signatures required to transfer this registry
+    pub threshold: u8,
     /// NFT name (padded to 32 bytes)
     pub name: [u8; 32],
     /// NFT symbol (padded to 10 bytes)
@@ -192,6 +830,36 @@ pub struct NFTRegistry {
     pub uri_hash: [u8; 32],
 }
 
+/// Caller-supplied snapshot of a registry's current on-chain state.
+///
+/// The compressed account's data isn't readable through normal Anchor account
+/// constraints, so the caller must fetch it from the indexer and pass it back
+/// in so `transfer_compressed_nft` can reconstruct the `LightAccount` it opens.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NFTRegistrySnapshot {
+    pub owners: Vec<[u8; 32]>,
+    pub threshold: u8,
+    pub name: [u8; 32],
+    pub symbol: [u8; 10],
+    pub uri_hash: [u8; 32],
+}
+
+impl NFTRegistrySnapshot {
+    fn into_registry(self) -> NFTRegistry {
+        let mut owners = [[0u8; 32]; MAX_OWNERS];
+        let owner_count = self.owners.len().min(MAX_OWNERS);
+        owners[..owner_count].copy_from_slice(&self.owners[..owner_count]);
+        NFTRegistry {
+            owners,
+            owner_count: owner_count as u8,
+            threshold: self.threshold,
+            name: self.name,
+            symbol: self.symbol,
+            uri_hash: self.uri_hash,
+        }
+    }
+}
+
 /// Simple hash function to convert arbitrary bytes to 32 bytes
 fn hash_to_32_bytes(data: &[u8]) -> [u8; 32] {
     use light_hasher::Poseidon;
@@ -216,4 +884,26 @@ pub enum ErrorCode {
     LightAccountError,
     #[msg("CPI invoke to Light System Program failed")]
     CpiInvokeFailed,
+    #[msg("Batch must contain at least one entry")]
+    EmptyBatch,
+    #[msg("Batch size exceeds MAX_BATCH_SIZE")]
+    BatchTooLarge,
+    #[msg("address_seeds length must match metas length")]
+    AddressSeedCountMismatch,
+    #[msg("expected_new_address_count must match metas length")]
+    NewAddressCountMismatch,
+    #[msg("remaining_accounts does not contain enough accounts for the address tree/output queue")]
+    InsufficientRemainingAccounts,
+    #[msg("Multisig config must satisfy 1 <= threshold <= owner_count <= MAX_OWNERS")]
+    InvalidMultisigConfig,
+    #[msg("Owner set contains a duplicate pubkey")]
+    DuplicateOwner,
+    #[msg("Not enough registered owners signed this transaction")]
+    ThresholdNotMet,
+    #[msg("diversifier_index must be less than 2^88")]
+    InvalidDiversifierIndex,
+    #[msg("Poseidon address seed derivation failed")]
+    SeedDerivationFailed,
+    #[msg("derivation path exceeds MAX_DERIVATION_PATH_LEN")]
+    DerivationPathTooLong,
 }